@@ -1,14 +1,542 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
 use std::net::TcpListener;
-use tauri::Manager;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::Local;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use tauri::{
+    CustomMenuItem, Manager, SystemTray, SystemTrayEvent, SystemTrayMenu, SystemTrayMenuItem,
+};
 use tauri::api::process::{Command, CommandEvent};
 
 struct BackendConfig {
     port: u16,
 }
 
+/// Structured message the Python sidecar emits as a single line of JSON on stdout.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum BackendMessage {
+    Log {
+        level: String,
+        text: String,
+    },
+    JobProgress {
+        job_id: String,
+        matched: u64,
+        unmatched: u64,
+        total: u64,
+        pct: f32,
+    },
+    JobDone {
+        job_id: String,
+        summary: String,
+    },
+}
+
+/// Latest reconciliation progress/status reported by the backend, for windows opened after the fact.
+struct BackendStatus(Mutex<Option<BackendMessage>>);
+
+impl BackendStatus {
+    fn new() -> Self {
+        Self(Mutex::new(None))
+    }
+}
+
+/// Return the most recent typed status/progress message reported by the backend, if any.
+#[tauri::command]
+fn get_backend_status(state: tauri::State<BackendStatus>) -> Option<BackendMessage> {
+    state.0.lock().ok().and_then(|guard| guard.clone())
+}
+
+/// Parse a raw stdout line from the sidecar as a `BackendMessage`; on success, emit a typed event
+/// (recording `JobProgress`/`JobDone` messages as the latest status), otherwise fall back to the
+/// plain string event.
+fn handle_backend_stdout(
+    app_handle: &tauri::AppHandle,
+    logs: &LogState,
+    status: &BackendStatus,
+    line: String,
+) {
+    println!("[PY] {}", line);
+    logs.write_line(&line);
+
+    match serde_json::from_str::<BackendMessage>(&line) {
+        Ok(message) => {
+            // Only progress/completion messages represent "current status" for a freshly opened
+            // window; a `Log` line interleaved after the last progress update shouldn't stomp it.
+            if matches!(message, BackendMessage::JobProgress { .. } | BackendMessage::JobDone { .. }) {
+                if let Ok(mut guard) = status.0.lock() {
+                    *guard = Some(message.clone());
+                }
+            }
+            let _ = app_handle.emit_all("backend-message", message);
+        }
+        Err(_) => {
+            let _ = app_handle.emit_all("backend-stdout", line);
+        }
+    }
+}
+
+/// Base delay for the restart backoff; doubled for each consecutive failure.
+const BACKOFF_BASE: Duration = Duration::from_millis(500);
+/// Upper bound on the restart backoff delay, regardless of how many failures in a row.
+const BACKOFF_CAP: Duration = Duration::from_secs(30);
+/// Random extra delay added on top of the computed backoff to avoid thundering-herd restarts.
+const BACKOFF_JITTER_MS: u64 = 250;
+/// How long the backend must stay connectable before a subsequent crash is treated as "fresh".
+const HEALTHY_RESET_THRESHOLD: Duration = Duration::from_secs(30);
+/// How long to wait for the backend to start accepting connections before treating it as a crash.
+const READINESS_TIMEOUT: Duration = Duration::from_secs(15);
+/// Interval between readiness probes while waiting for the backend to bind its port.
+const READINESS_POLL_INTERVAL: Duration = Duration::from_millis(200);
+/// How often to poll the configured update feed in the background.
+const UPDATE_CHECK_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// Compute the next restart delay given how many consecutive failures have occurred.
+fn backoff_delay(consecutive_failures: u32) -> Duration {
+    let exponent = consecutive_failures.min(8);
+    let scaled = BACKOFF_BASE.saturating_mul(1u32 << exponent);
+    let capped = scaled.min(BACKOFF_CAP);
+    let jitter = Duration::from_millis(rand::thread_rng().gen_range(0..=BACKOFF_JITTER_MS));
+    capped + jitter
+}
+
+/// Current lifecycle state of the Python sidecar, as seen by the tray icon.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BackendState {
+    Starting,
+    Running,
+    Crashed,
+    Restarting,
+}
+
+impl BackendState {
+    fn tooltip(&self) -> &'static str {
+        match self {
+            BackendState::Starting => "Conciliacion Financiera — backend starting...",
+            BackendState::Running => "Conciliacion Financiera — backend running",
+            BackendState::Crashed => "Conciliacion Financiera — backend crashed",
+            BackendState::Restarting => "Conciliacion Financiera — restarting backend...",
+        }
+    }
+
+    fn status_label(&self) -> String {
+        format!(
+            "Status: {}",
+            match self {
+                BackendState::Starting => "Starting",
+                BackendState::Running => "Running",
+                BackendState::Crashed => "Crashed",
+                BackendState::Restarting => "Restarting",
+            }
+        )
+    }
+}
+
+/// Shared handle the tray and the supervisor loop both use to track/drive the backend.
+struct BackendControl {
+    state: Mutex<BackendState>,
+    child: Mutex<Option<tauri::api::process::CommandChild>>,
+    /// Set when the supervisor loop should stop restarting the sidecar altogether, e.g. because
+    /// an update is about to be installed and the new binary needs the port free.
+    shutdown: AtomicBool,
+    /// How many restart attempts in a row have failed; drives the backoff delay.
+    consecutive_failures: AtomicU32,
+    /// Woken up to cut a pending backoff sleep short, e.g. on a manual "Restart Backend" click.
+    retry_notify: tokio::sync::Notify,
+}
+
+impl BackendControl {
+    fn new() -> Arc<Self> {
+        Arc::new(Self {
+            state: Mutex::new(BackendState::Starting),
+            child: Mutex::new(None),
+            shutdown: AtomicBool::new(false),
+            consecutive_failures: AtomicU32::new(0),
+            retry_notify: tokio::sync::Notify::new(),
+        })
+    }
+
+    fn set_state(&self, app_handle: &tauri::AppHandle, new_state: BackendState) {
+        if let Ok(mut state) = self.state.lock() {
+            *state = new_state;
+        }
+        update_tray(app_handle, new_state);
+    }
+
+    /// Ask the supervisor loop to stop and kill the sidecar immediately, without waiting for it
+    /// to exit on its own. Used before installing an update so the new binary can bind the port.
+    fn stop_and_kill(&self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        if let Ok(mut slot) = self.child.lock() {
+            if let Some(child) = slot.take() {
+                let _ = child.kill();
+            }
+        }
+    }
+
+    /// Clear a previously requested shutdown so the supervisor loop can be restarted.
+    fn resume(&self) {
+        self.shutdown.store(false, Ordering::SeqCst);
+    }
+
+    /// Kill the sidecar (if running) and wake the supervisor loop immediately, resetting the
+    /// backoff counter so a manual restart never inherits a climbing delay from a prior crash
+    /// loop — and so it also works while the loop is already asleep between attempts.
+    fn restart_now(&self) {
+        if let Ok(mut slot) = self.child.lock() {
+            if let Some(child) = slot.take() {
+                let _ = child.kill();
+            }
+        }
+        self.consecutive_failures.store(0, Ordering::SeqCst);
+        self.retry_notify.notify_one();
+    }
+}
+
+/// Spawn (or respawn, e.g. after a failed update install) the sidecar monitoring loop.
+fn spawn_backend_supervisor(app_handle: tauri::AppHandle, port: u16) {
+    let port_arg = port.to_string();
+
+    tauri::async_runtime::spawn(async move {
+        let logs = app_handle.state::<LogState>();
+        let control = app_handle.state::<Arc<BackendControl>>();
+        let status = app_handle.state::<BackendStatus>();
+
+        loop {
+            if control.shutdown.load(Ordering::SeqCst) {
+                let msg = "[Rust] Shutdown requested; stopping backend supervisor loop.";
+                println!("{}", msg);
+                logs.write_line(msg);
+                break;
+            }
+
+            let msg = format!("[Rust] Spawning backend sidecar on port {}...", port_arg);
+            println!("{}", msg);
+            logs.write_line(&msg);
+            let _ = app_handle.emit_all("backend-stdout", msg);
+
+            let cmd = Command::new_sidecar("conciliacion-backend")
+                .expect("failed to create `conciliacion-backend` binary command")
+                .args(&["--port", &port_arg]);
+
+            let should_reset_backoff = match cmd.spawn() {
+                Ok((mut rx, child)) => {
+                    if let Ok(mut slot) = control.child.lock() {
+                        *slot = Some(child);
+                    }
+
+                    // Wait for the sidecar to actually bind its port before declaring success —
+                    // a successful spawn() does not guarantee a successful bind. `interval`
+                    // ticks on a fixed schedule (unlike a sleep recreated every loop iteration),
+                    // so a chatty backend can't starve the readiness probe of chances to run.
+                    let ready_deadline = tokio::time::Instant::now() + READINESS_TIMEOUT;
+                    let mut interval = tokio::time::interval(READINESS_POLL_INTERVAL);
+                    // Set the instant the backend actually starts serving, not when it was
+                    // spawned — the up-to-`READINESS_TIMEOUT` wait beforehand isn't "healthy" time.
+                    let mut ready_since: Option<tokio::time::Instant> = None;
+
+                    while ready_since.is_none() && tokio::time::Instant::now() < ready_deadline {
+                        tokio::select! {
+                            event = rx.recv() => {
+                                match event {
+                                    Some(CommandEvent::Stdout(line)) => {
+                                        handle_backend_stdout(&app_handle, &logs, &status, line);
+                                    }
+                                    Some(CommandEvent::Stderr(line)) => {
+                                        eprintln!("[PY ERR] {}", line);
+                                        logs.write_line(&line);
+                                        let _ = app_handle.emit_all("backend-stderr", line);
+                                    }
+                                    Some(_) => {}
+                                    None => break, // process exited before it ever became ready
+                                }
+                            }
+                            _ = interval.tick() => {
+                                if tokio::net::TcpStream::connect(("127.0.0.1", port)).await.is_ok() {
+                                    ready_since = Some(tokio::time::Instant::now());
+                                }
+                            }
+                        }
+                    }
+
+                    if let Some(ready_since) = ready_since {
+                        println!("[Rust] Backend started successfully");
+                        logs.write_line("[Rust] Backend started successfully");
+                        let _ = app_handle.emit_all("backend-stdout", "[Rust] Backend started successfully");
+                        control.set_state(&app_handle, BackendState::Running);
+
+                        // Process output until the process exits
+                        while let Some(event) = rx.recv().await {
+                            match event {
+                                CommandEvent::Stdout(line) => {
+                                    handle_backend_stdout(&app_handle, &logs, &status, line);
+                                }
+                                CommandEvent::Stderr(line) => {
+                                    eprintln!("[PY ERR] {}", line);
+                                    logs.write_line(&line);
+                                    let _ = app_handle.emit_all("backend-stderr", line);
+                                }
+                                _ => {}
+                            }
+                        }
+
+                        if let Ok(mut slot) = control.child.lock() {
+                            *slot = None;
+                        }
+                        let msg = "[Rust] Backend process exited unexpectedly.";
+                        println!("{}", msg);
+                        logs.write_line(msg);
+                        let _ = app_handle.emit_all("backend-stderr", msg);
+
+                        // A crash right after a long, healthy run isn't part of a crash loop —
+                        // treat it as a fresh failure rather than compounding backoff.
+                        ready_since.elapsed() >= HEALTHY_RESET_THRESHOLD
+                    } else {
+                        let msg = format!(
+                            "[Rust] Backend did not bind port {} within {:?}; treating as crashed",
+                            port, READINESS_TIMEOUT
+                        );
+                        eprintln!("{}", msg);
+                        logs.write_line(&msg);
+                        let _ = app_handle.emit_all("backend-stderr", msg);
+                        if let Ok(mut slot) = control.child.lock() {
+                            if let Some(child) = slot.take() {
+                                let _ = child.kill();
+                            }
+                        }
+                        false
+                    }
+                }
+                Err(e) => {
+                    eprintln!("[Rust] Failed to spawn sidecar: {}", e);
+                    let msg = format!("[Rust] Failed to spawn sidecar: {}", e);
+                    logs.write_line(&msg);
+                    let _ = app_handle.emit_all("backend-stderr", msg);
+                    false
+                }
+            };
+
+            control.set_state(&app_handle, BackendState::Crashed);
+
+            let consecutive_failures = if should_reset_backoff {
+                control.consecutive_failures.store(0, Ordering::SeqCst);
+                0
+            } else {
+                control.consecutive_failures.fetch_add(1, Ordering::SeqCst) + 1
+            };
+            let delay = backoff_delay(consecutive_failures);
+            let msg = format!(
+                "[Rust] retrying in {:.1}s (attempt {})",
+                delay.as_secs_f32(),
+                consecutive_failures
+            );
+            println!("{}", msg);
+            logs.write_line(&msg);
+            let _ = app_handle.emit_all("backend-stderr", msg);
+
+            if control.shutdown.load(Ordering::SeqCst) {
+                let msg = "[Rust] Shutdown requested; stopping backend supervisor loop.";
+                println!("{}", msg);
+                logs.write_line(msg);
+                break;
+            }
+
+            control.set_state(&app_handle, BackendState::Restarting);
+            // A manual "Restart Backend" click wakes this immediately instead of waiting out
+            // the rest of the backoff delay.
+            tokio::select! {
+                _ = tokio::time::sleep(delay) => {}
+                _ = control.retry_notify.notified() => {
+                    let msg = "[Rust] Manual restart requested; skipping remaining backoff.";
+                    println!("{}", msg);
+                    logs.write_line(msg);
+                }
+            }
+        }
+    });
+}
+
+fn build_system_tray() -> SystemTray {
+    let menu = SystemTrayMenu::new()
+        .add_item(CustomMenuItem::new("status".to_string(), "Status: Starting").disabled())
+        .add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(CustomMenuItem::new("show".to_string(), "Show Window"))
+        .add_item(CustomMenuItem::new("restart".to_string(), "Restart Backend"))
+        .add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(CustomMenuItem::new("quit".to_string(), "Quit"));
+
+    SystemTray::new().with_menu(menu).with_tooltip(BackendState::Starting.tooltip())
+}
+
+/// Reflect the backend's current state in the tray tooltip and status menu item.
+fn update_tray(app_handle: &tauri::AppHandle, state: BackendState) {
+    let tray = app_handle.tray_handle();
+    let _ = tray.set_tooltip(state.tooltip());
+    let _ = tray.get_item("status").set_title(state.status_label());
+}
+
+fn handle_system_tray_event(app_handle: &tauri::AppHandle, event: SystemTrayEvent) {
+    match event {
+        SystemTrayEvent::MenuItemClick { id, .. } => match id.as_str() {
+            "show" => {
+                if let Some(window) = app_handle.get_window("main") {
+                    let _ = window.show();
+                    let _ = window.set_focus();
+                }
+            }
+            "restart" => {
+                let control = app_handle.state::<Arc<BackendControl>>();
+                control.set_state(app_handle, BackendState::Restarting);
+                control.restart_now();
+            }
+            "quit" => std::process::exit(0),
+            _ => {}
+        },
+        SystemTrayEvent::LeftClick { .. } => {
+            if let Some(window) = app_handle.get_window("main") {
+                let _ = window.show();
+                let _ = window.set_focus();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Maximum number of rotated log files to keep around.
+const MAX_LOG_FILES: usize = 14;
+/// Roll over to a new file once the current one passes this size, even within the same day.
+const MAX_LOG_SIZE_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Tracks the currently open log file so writes can append without reopening it every line.
+/// `dir` is `None` when the log directory couldn't be resolved at startup (e.g. a sandboxed
+/// environment with no app data dir); in that case logging is silently disabled rather than
+/// gating the rest of the app on a "nice to have for bug reports" feature.
+struct LogState {
+    dir: Option<PathBuf>,
+    current: Mutex<Option<(String, File)>>,
+}
+
+impl LogState {
+    fn new(dir: Option<PathBuf>) -> Self {
+        Self {
+            dir,
+            current: Mutex::new(None),
+        }
+    }
+
+    /// Append a line to today's log file, rotating by date or size and pruning old files.
+    /// No-op if the log directory is unavailable.
+    fn write_line(&self, line: &str) {
+        let Some(dir) = self.dir.as_ref() else {
+            return;
+        };
+
+        let mut guard = match self.current.lock() {
+            Ok(guard) => guard,
+            Err(_) => return,
+        };
+
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        let needs_new_file = match guard.as_ref() {
+            None => true,
+            Some((stem, file)) => {
+                stem != &today || file.metadata().map(|m| m.len()).unwrap_or(0) > MAX_LOG_SIZE_BYTES
+            }
+        };
+
+        if needs_new_file {
+            match Self::open_fresh_file(dir, &today) {
+                Ok(file) => *guard = Some((today.clone(), file)),
+                Err(e) => {
+                    eprintln!("[Rust] Failed to open log file: {}", e);
+                    return;
+                }
+            }
+            Self::prune_old_files(dir);
+        }
+
+        if let Some((_, file)) = guard.as_mut() {
+            let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S%.3f");
+            let _ = writeln!(file, "[{}] {}", timestamp, line);
+        }
+    }
+
+    fn open_fresh_file(dir: &std::path::Path, date_stem: &str) -> std::io::Result<File> {
+        fs::create_dir_all(dir)?;
+        let mut index = 0u32;
+        loop {
+            let name = if index == 0 {
+                format!("backend-{}.log", date_stem)
+            } else {
+                format!("backend-{}.{}.log", date_stem, index)
+            };
+            let path = dir.join(&name);
+            let existing_size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+            if existing_size <= MAX_LOG_SIZE_BYTES {
+                return OpenOptions::new().create(true).append(true).open(path);
+            }
+            index += 1;
+        }
+    }
+
+    /// Keep only the most recently modified `MAX_LOG_FILES` files in the log directory.
+    fn prune_old_files(dir: &std::path::Path) {
+        let mut entries: Vec<_> = match fs::read_dir(dir) {
+            Ok(read_dir) => read_dir.filter_map(|e| e.ok()).collect(),
+            Err(_) => return,
+        };
+
+        entries.sort_by_key(|e| {
+            e.metadata()
+                .and_then(|m| m.modified())
+                .unwrap_or(std::time::SystemTime::UNIX_EPOCH)
+        });
+
+        while entries.len() > MAX_LOG_FILES {
+            let oldest = entries.remove(0);
+            let _ = fs::remove_file(oldest.path());
+        }
+    }
+}
+
+/// Return the path to the most recently modified backend log file, if any exist.
+#[tauri::command]
+fn get_last_log_file(app_handle: tauri::AppHandle) -> Option<String> {
+    let dir = log_dir(&app_handle).ok()?;
+    let entries = fs::read_dir(&dir).ok()?;
+
+    entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().map(|ext| ext == "log").unwrap_or(false))
+        .max_by_key(|e| {
+            e.metadata()
+                .and_then(|m| m.modified())
+                .unwrap_or(std::time::SystemTime::UNIX_EPOCH)
+        })
+        .map(|e| e.path().to_string_lossy().to_string())
+}
+
+/// Resolve (and create) the directory backend logs are written to.
+fn log_dir(app_handle: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let base = app_handle
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Could not get app data directory".to_string())?;
+    let dir = base.join("logs");
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
 #[tauri::command]
 fn get_backend_port(state: tauri::State<BackendConfig>) -> u16 {
     state.port
@@ -24,54 +552,63 @@ fn main() {
 
     tauri::Builder::default()
         .manage(BackendConfig { port })
+        .system_tray(build_system_tray())
+        .on_system_tray_event(|app, event| handle_system_tray_event(&app.app_handle(), event))
         .setup(move |app| {
             let window = app.get_window("main").unwrap();
             let app_handle = app.handle();
-            let port_arg = port.to_string();
-            
+
+            let log_state = match log_dir(&app_handle) {
+                Ok(dir) => LogState::new(Some(dir)),
+                Err(e) => {
+                    eprintln!(
+                        "[Rust] Could not resolve backend log directory; log persistence disabled: {}",
+                        e
+                    );
+                    LogState::new(None)
+                }
+            };
+            app.manage(log_state);
+
+            let backend_control = BackendControl::new();
+            app.manage(backend_control);
+
+            app.manage(BackendStatus::new());
+
             // Spawn the sidecar (Python backend) in a monitoring loop
+            spawn_backend_supervisor(app_handle.clone(), port);
+
+            // Periodically check for application updates. The feed URL and the on/off switch
+            // live in `tauri.conf.json` (`tauri.updater.endpoints` / `tauri.updater.active`) so
+            // banking deployments that are pinned to a specific build can simply disable this.
+            let updater_handle = app.handle();
             tauri::async_runtime::spawn(async move {
+                let logs = updater_handle.state::<LogState>();
+
+                if !updater_handle.config().tauri.updater.active {
+                    let msg = "[Rust] Auto-update is disabled in configuration; skipping update checks.";
+                    println!("{}", msg);
+                    logs.write_line(msg);
+                    return;
+                }
+
                 loop {
-                    println!("[Rust] Spawning backend sidecar on port {}...", port_arg);
-                    let _ = app_handle.emit_all("backend-stdout", format!("[Rust] Spawning backend sidecar on port {}...", port_arg));
-
-                    let cmd = Command::new_sidecar("conciliacion-backend")
-                        .expect("failed to create `conciliacion-backend` binary command")
-                        .args(&["--port", &port_arg]);
-                        
-                    match cmd.spawn() {
-                        Ok((mut rx, _child)) => {
-                            println!("[Rust] Backend started successfully");
-                            let _ = app_handle.emit_all("backend-stdout", "[Rust] Backend started successfully");
-                            
-                            // Process output until the process exits
-                            while let Some(event) = rx.recv().await {
-                                match event {
-                                    CommandEvent::Stdout(line) => {
-                                        println!("[PY] {}", line);
-                                        let _ = app_handle.emit_all("backend-stdout", line);
-                                    }
-                                    CommandEvent::Stderr(line) => {
-                                        eprintln!("[PY ERR] {}", line);
-                                        let _ = app_handle.emit_all("backend-stderr", line);
-                                    }
-                                    _ => {}
-                                }
-                            }
-                            
-                            println!("[Rust] Backend process exited unexpectedly. Restarting in 2 seconds...");
-                            let _ = app_handle.emit_all("backend-stderr", "[Rust] Backend process exited unexpectedly. Restarting in 2 seconds...");
+                    match tauri::updater::builder(updater_handle.clone()).check().await {
+                        Ok(update) if update.is_update_available() => {
+                            let msg = format!("[Rust] Update available: {}", update.latest_version());
+                            println!("{}", msg);
+                            logs.write_line(&msg);
+                            let _ = updater_handle.emit_all("update-available", update.latest_version().to_string());
                         }
+                        Ok(_) => {}
                         Err(e) => {
-                            eprintln!("[Rust] Failed to spawn sidecar: {}", e);
-                             let msg = format!("[Rust] Failed to spawn sidecar: {}", e);
-                            let _ = app_handle.emit_all("backend-stderr", msg);
-                            println!("[Rust] Retrying in 2 seconds...");
+                            let msg = format!("[Rust] Update check failed: {}", e);
+                            eprintln!("{}", msg);
+                            logs.write_line(&msg);
                         }
                     }
-                    
-                    // Wait before restarting to prevent crash loops
-                    std::thread::sleep(std::time::Duration::from_secs(2));
+
+                    tokio::time::sleep(UPDATE_CHECK_INTERVAL).await;
                 }
             });
 
@@ -99,9 +636,13 @@ fn main() {
         })
         .invoke_handler(tauri::generate_handler![
             get_app_data_dir,
-            open_folder_in_finder,
+            reveal_path,
             show_notification,
-            get_backend_port
+            get_backend_port,
+            get_last_log_file,
+            get_backend_status,
+            check_for_update,
+            install_update
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -117,24 +658,87 @@ fn get_app_data_dir(app_handle: tauri::AppHandle) -> Result<String, String> {
         .ok_or_else(|| "Could not get app data directory".to_string())
 }
 
-/// Open a folder in Finder (macOS)
+/// Reveal a file or folder in the OS file manager, selecting it if a specific file was given.
 #[tauri::command]
-fn open_folder_in_finder(path: String) -> Result<(), String> {
+fn reveal_path(path: String) -> Result<(), String> {
+    let target = std::path::Path::new(&path);
+    if !target.exists() {
+        return Err(format!("Path does not exist: {}", path));
+    }
+
     #[cfg(target_os = "macos")]
     {
         std::process::Command::new("open")
-        .arg(&path)
-        .spawn()
-        .map_err(|e| e.to_string())?;
+            .arg("-R")
+            .arg(&path)
+            .spawn()
+            .map_err(|e| e.to_string())?;
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        if target.is_dir() {
+            std::process::Command::new("explorer")
+                .arg(&path)
+                .spawn()
+                .map_err(|e| e.to_string())?;
+        } else {
+            std::process::Command::new("explorer")
+                .arg(format!("/select,{}", path))
+                .spawn()
+                .map_err(|e| e.to_string())?;
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        reveal_path_linux(target)?;
     }
-    
-    // For other platforms we just ignore or log
-    #[cfg(not(target_os = "macos"))]
-    { let _ = path; }
 
     Ok(())
 }
 
+/// Linux has no single standard "reveal in file manager" call, so try the freedesktop
+/// `FileManager1.ShowItems` D-Bus method (which can select a specific file) first, then fall
+/// back to plain `xdg-open` on the folder itself, or on the containing folder for a file.
+#[cfg(target_os = "linux")]
+fn reveal_path_linux(target: &std::path::Path) -> Result<(), String> {
+    if which::which("dbus-send").is_ok() {
+        let uri = format!("file://{}", target.to_string_lossy());
+        let status = std::process::Command::new("dbus-send")
+            .args(&[
+                "--session",
+                "--print-reply",
+                "--dest=org.freedesktop.FileManager1",
+                "--type=method_call",
+                "/org/freedesktop/FileManager1",
+                "org.freedesktop.FileManager1.ShowItems",
+                &format!("array:string:{}", uri),
+                "string:",
+            ])
+            .status()
+            .map_err(|e| e.to_string())?;
+        if status.success() {
+            return Ok(());
+        }
+    }
+
+    if let Ok(xdg_open) = which::which("xdg-open") {
+        let open_target = if target.is_dir() {
+            target
+        } else {
+            target.parent().unwrap_or(target)
+        };
+        std::process::Command::new(xdg_open)
+            .arg(open_target)
+            .spawn()
+            .map_err(|e| e.to_string())?;
+        return Ok(());
+    }
+
+    Err("No file manager launcher found on this system (tried dbus-send and xdg-open)".to_string())
+}
+
 /// Show a native notification
 #[tauri::command]
 fn show_notification(title: String, body: String) -> Result<(), String> {
@@ -144,3 +748,165 @@ fn show_notification(title: String, body: String) -> Result<(), String> {
         .show()
         .map_err(|e| e.to_string())
 }
+
+/// Query the configured update feed and report whether a newer build is available.
+#[tauri::command]
+async fn check_for_update(app_handle: tauri::AppHandle) -> Result<bool, String> {
+    let update = tauri::updater::builder(app_handle.clone())
+        .check()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let available = update.is_update_available();
+    if available {
+        let _ = app_handle.emit_all("update-available", update.latest_version().to_string());
+    }
+    Ok(available)
+}
+
+/// Download and install the latest update, then relaunch. The sidecar supervisor loop is only
+/// signalled to stop (and its child killed) once an update is confirmed available, so the new
+/// backend binary on disk isn't left blocked behind the still-running old one — and if the
+/// download/install itself fails, the backend is resumed rather than left down for the rest of
+/// the session.
+#[tauri::command]
+async fn install_update(app_handle: tauri::AppHandle) -> Result<(), String> {
+    let update = tauri::updater::builder(app_handle.clone())
+        .check()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if !update.is_update_available() {
+        return Err("No update is currently available".to_string());
+    }
+
+    let port = app_handle.state::<BackendConfig>().port;
+    let control = app_handle.state::<Arc<BackendControl>>();
+    control.stop_and_kill();
+    let _ = app_handle.emit_all("update-progress", "Stopping backend before installing update...");
+
+    let _ = app_handle.emit_all("update-progress", "Downloading update...");
+    if let Err(e) = update.download_and_install().await {
+        control.resume();
+        spawn_backend_supervisor(app_handle.clone(), port);
+        return Err(e.to_string());
+    }
+
+    let _ = app_handle.emit_all("update-ready", ());
+    tauri::api::process::restart(&app_handle.env());
+}
+
+#[cfg(test)]
+mod log_state_tests {
+    use super::*;
+
+    /// A fresh, empty directory under the OS temp dir, scoped to one test by name.
+    fn temp_log_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("conciliacion-log-tests-{}", name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn write_line_is_a_no_op_without_a_resolved_directory() {
+        let logs = LogState::new(None);
+        // Must not panic even though there is nowhere to write.
+        logs.write_line("hello");
+    }
+
+    #[test]
+    fn write_line_rotates_to_a_new_file_past_the_size_cap() {
+        let dir = temp_log_dir("rotate-size");
+        let logs = LogState::new(Some(dir.clone()));
+
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        let oversized_path = dir.join(format!("backend-{}.log", today));
+        fs::write(&oversized_path, vec![b'x'; (MAX_LOG_SIZE_BYTES + 1) as usize]).unwrap();
+
+        logs.write_line("first line after rotation");
+
+        assert!(dir.join(format!("backend-{}.1.log", today)).exists());
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn prune_old_files_trims_down_to_the_cap() {
+        let dir = temp_log_dir("prune-trims");
+        for i in 0..(MAX_LOG_FILES + 3) {
+            fs::write(dir.join(format!("backend-2020-01-{:02}.log", i + 1)), b"x").unwrap();
+        }
+
+        LogState::prune_old_files(&dir);
+
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), MAX_LOG_FILES);
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn prune_old_files_is_a_no_op_at_or_below_the_cap() {
+        let dir = temp_log_dir("prune-noop");
+        for i in 0..MAX_LOG_FILES {
+            fs::write(dir.join(format!("backend-2020-01-{:02}.log", i + 1)), b"x").unwrap();
+        }
+
+        LogState::prune_old_files(&dir);
+
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), MAX_LOG_FILES);
+        fs::remove_dir_all(&dir).ok();
+    }
+}
+
+#[cfg(test)]
+mod supervisor_tests {
+    use super::*;
+
+    #[test]
+    fn backoff_delay_doubles_with_each_failure_before_the_cap() {
+        for failures in 0..8 {
+            let min_expected =
+                (BACKOFF_BASE.as_millis() * (1u128 << failures)).min(BACKOFF_CAP.as_millis());
+            let delay = backoff_delay(failures);
+            assert!(delay.as_millis() >= min_expected);
+            assert!(delay.as_millis() <= min_expected + BACKOFF_JITTER_MS as u128);
+        }
+    }
+
+    #[test]
+    fn backoff_delay_never_exceeds_the_cap_plus_jitter() {
+        for failures in [8, 9, 20, u32::MAX] {
+            let delay = backoff_delay(failures);
+            assert!(delay <= BACKOFF_CAP + Duration::from_millis(BACKOFF_JITTER_MS));
+        }
+    }
+
+    #[test]
+    fn backend_message_uses_snake_case_type_tags() {
+        let msg = BackendMessage::JobProgress {
+            job_id: "job-1".to_string(),
+            matched: 3,
+            unmatched: 1,
+            total: 4,
+            pct: 75.0,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"type\":\"job_progress\""));
+
+        let parsed: BackendMessage = serde_json::from_str(&json).unwrap();
+        match parsed {
+            BackendMessage::JobProgress { job_id, matched, unmatched, total, pct } => {
+                assert_eq!(job_id, "job-1");
+                assert_eq!(matched, 3);
+                assert_eq!(unmatched, 1);
+                assert_eq!(total, 4);
+                assert_eq!(pct, 75.0);
+            }
+            other => panic!("unexpected variant: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn non_json_stdout_lines_do_not_parse_as_backend_messages() {
+        assert!(serde_json::from_str::<BackendMessage>("not json").is_err());
+    }
+}